@@ -1,54 +1,48 @@
-use assert_matches::assert_matches;
 use zksync_types::U256;
 
 use crate::{
-    interface::{ExecutionResult, Halt, TxExecutionMode, VmExecutionMode, VmInterface},
+    interface::{Halt, TxExecutionMode, VmExecutionMode, VmInterface},
     versions::vm_fast::tests::{
         tester::VmTesterBuilder,
-        utils::{get_bootloader, verify_required_memory, BASE_SYSTEM_CONTRACTS},
+        utils::{assert_halt, get_bootloader, BASE_SYSTEM_CONTRACTS},
     },
 };
 
 #[test]
 fn test_dummy_bootloader() {
     let mut base_system_contracts = BASE_SYSTEM_CONTRACTS.clone();
-    base_system_contracts.bootloader = get_bootloader("dummy");
+    base_system_contracts.bootloader = get_bootloader("dummy").unwrap();
+
+    let correct_first_cell = U256::from_str_radix("123123123", 16).unwrap();
 
     let mut vm = VmTesterBuilder::new()
         .with_empty_in_memory_storage()
         .with_base_system_smart_contracts(base_system_contracts)
         .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_heap_assertions(vec![(correct_first_cell, vm2::FIRST_HEAP, 0)])
+        .with_no_storage_writes()
         .build();
 
     let result = vm.vm.execute(VmExecutionMode::Batch);
     assert!(!result.result.is_failed());
 
-    let correct_first_cell = U256::from_str_radix("123123123", 16).unwrap();
-
-    verify_required_memory(
-        &vm.vm.inner.state,
-        vec![(correct_first_cell, vm2::FIRST_HEAP, 0)],
-    );
+    vm.verify_heap_assertions();
+    vm.verify_storage_writes();
 }
 
 #[test]
 fn test_bootloader_out_of_gas() {
     let mut base_system_contracts = BASE_SYSTEM_CONTRACTS.clone();
-    base_system_contracts.bootloader = get_bootloader("dummy");
+    base_system_contracts.bootloader = get_bootloader("dummy").unwrap();
 
     let mut vm = VmTesterBuilder::new()
         .with_empty_in_memory_storage()
         .with_base_system_smart_contracts(base_system_contracts)
-        .with_bootloader_gas_limit(10)
+        .with_bootloader_gas_limit_exhausted()
         .with_execution_mode(TxExecutionMode::VerifyExecute)
         .build();
 
     let res = vm.vm.execute(VmExecutionMode::Batch);
 
-    assert_matches!(
-        res.result,
-        ExecutionResult::Halt {
-            reason: Halt::BootloaderOutOfGas
-        }
-    );
+    assert_halt(&res.result, Halt::BootloaderOutOfGas);
 }
@@ -1,6 +1,6 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
-use vm2::WorldDiff;
+use vm2::{HeapId, WorldDiff};
 use zksync_contracts::BaseSystemContracts;
 use zksync_test_account::{Account, TxType};
 use zksync_types::{
@@ -19,7 +19,10 @@ use crate::{
         storage::{InMemoryStorage, StoragePtr},
         L1BatchEnv, L2Block, L2BlockEnv, SystemEnv, TxExecutionMode, VmExecutionMode, VmInterface,
     },
-    versions::vm_fast::{tests::utils::read_test_contract, vm::Vm},
+    versions::vm_fast::{
+        tests::utils::{read_test_contract, verify_required_memory},
+        vm::Vm,
+    },
     vm_latest::{constants::BATCH_COMPUTATIONAL_GAS_LIMIT, utils::l2_blocks::load_last_l2_block},
 };
 
@@ -31,6 +34,9 @@ pub(crate) struct VmTester {
     pub(crate) fee_account: Address,
     pub(crate) rich_accounts: Vec<Account>,
     pub(crate) custom_contracts: Vec<ContractsToDeploy>,
+    pub(crate) heap_assertions: Vec<HeapAssertion>,
+    pub(crate) expected_storage_writes: Option<StorageWrites>,
+    initial_storage_state: StorageWrites,
 }
 
 impl VmTester {
@@ -50,6 +56,31 @@ impl VmTester {
         self.test_contract = Some(deployed_address);
     }
 
+    /// Checks the heap cells registered via [`VmTesterBuilder::with_heap_assertions()`],
+    /// listing every mismatching cell on failure.
+    pub(crate) fn verify_heap_assertions(&self) {
+        verify_required_memory(&self.vm.inner.state, &self.heap_assertions);
+    }
+
+    /// Checks that the storage slots modified since the tester was built match the ones
+    /// registered via [`VmTesterBuilder::with_expected_storage_writes()`].
+    pub(crate) fn verify_storage_writes(&self) {
+        let expected = self
+            .expected_storage_writes
+            .as_ref()
+            .expect("Expected storage writes must be set in the builder");
+        let writes: StorageWrites = self
+            .vm
+            .inner
+            .world_diff
+            .get_storage_state()
+            .iter()
+            .filter(|&(slot, value)| self.initial_storage_state.get(slot) != Some(value))
+            .map(|(&slot, &value)| (slot, value))
+            .collect();
+        assert_eq!(&writes, expected, "Unexpected storage writes");
+    }
+
     pub(crate) fn reset_with_empty_storage(&mut self) {
         self.storage = Rc::new(RefCell::new(get_empty_storage()));
         self.vm.inner.world_diff = WorldDiff::default();
@@ -109,6 +140,15 @@ impl VmTester {
 
 pub(crate) type ContractsToDeploy = (Vec<u8>, Address, bool);
 
+/// Computational gas limit too small for any bootloader to finish the batch.
+const EXHAUSTED_BOOTLOADER_GAS_LIMIT: u32 = 10;
+
+/// Expected value of a heap cell: `(value, heap, cell index)`.
+pub(crate) type HeapAssertion = (U256, HeapId, u32);
+
+/// Storage slot values keyed by `(address, key)`.
+pub(crate) type StorageWrites = BTreeMap<(Address, U256), U256>;
+
 pub(crate) struct VmTesterBuilder {
     storage: Option<InMemoryStorage>,
     l1_batch_env: Option<L1BatchEnv>,
@@ -116,6 +156,8 @@ pub(crate) struct VmTesterBuilder {
     deployer: Option<Account>,
     rich_accounts: Vec<Account>,
     custom_contracts: Vec<ContractsToDeploy>,
+    heap_assertions: Vec<HeapAssertion>,
+    expected_storage_writes: Option<StorageWrites>,
 }
 
 impl Clone for VmTesterBuilder {
@@ -127,6 +169,8 @@ impl Clone for VmTesterBuilder {
             deployer: self.deployer.clone(),
             rich_accounts: self.rich_accounts.clone(),
             custom_contracts: self.custom_contracts.clone(),
+            heap_assertions: self.heap_assertions.clone(),
+            expected_storage_writes: self.expected_storage_writes.clone(),
         }
     }
 }
@@ -149,6 +193,8 @@ impl VmTesterBuilder {
             deployer: None,
             rich_accounts: vec![],
             custom_contracts: vec![],
+            heap_assertions: vec![],
+            expected_storage_writes: None,
         }
     }
 
@@ -175,11 +221,18 @@ impl VmTesterBuilder {
         self
     }
 
+    /// Sets the computational gas available to the bootloader for the whole batch.
     pub(crate) fn with_bootloader_gas_limit(mut self, gas_limit: u32) -> Self {
         self.system_env.bootloader_gas_limit = gas_limit;
         self
     }
 
+    /// Sets a bootloader gas limit that is guaranteed to be exhausted, so the batch halts
+    /// with `Halt::BootloaderOutOfGas`.
+    pub(crate) fn with_bootloader_gas_limit_exhausted(self) -> Self {
+        self.with_bootloader_gas_limit(EXHAUSTED_BOOTLOADER_GAS_LIMIT)
+    }
+
     pub(crate) fn with_execution_mode(mut self, execution_mode: TxExecutionMode) -> Self {
         self.system_env.execution_mode = execution_mode;
         self
@@ -214,6 +267,25 @@ impl VmTesterBuilder {
         self
     }
 
+    /// Registers heap cells that are expected after execution; they are checked by
+    /// [`VmTester::verify_heap_assertions()`].
+    pub(crate) fn with_heap_assertions(mut self, assertions: Vec<HeapAssertion>) -> Self {
+        self.heap_assertions.extend(assertions);
+        self
+    }
+
+    /// Registers the storage writes the test expects; they are checked by
+    /// [`VmTester::verify_storage_writes()`].
+    pub(crate) fn with_expected_storage_writes(mut self, writes: StorageWrites) -> Self {
+        self.expected_storage_writes = Some(writes);
+        self
+    }
+
+    /// Expects execution not to modify storage at all.
+    pub(crate) fn with_no_storage_writes(self) -> Self {
+        self.with_expected_storage_writes(StorageWrites::new())
+    }
+
     pub(crate) fn build(self) -> VmTester {
         let l1_batch_env = self
             .l1_batch_env
@@ -231,6 +303,7 @@ impl VmTesterBuilder {
 
         let fee_account = l1_batch_env.fee_account;
         let vm = Vm::new(l1_batch_env, self.system_env, storage_ptr.clone());
+        let initial_storage_state = vm.inner.world_diff.get_storage_state().clone();
 
         VmTester {
             vm,
@@ -240,6 +313,9 @@ impl VmTesterBuilder {
             fee_account,
             rich_accounts: self.rich_accounts.clone(),
             custom_contracts: self.custom_contracts.clone(),
+            heap_assertions: self.heap_assertions,
+            expected_storage_writes: self.expected_storage_writes,
+            initial_storage_state,
         }
     }
 }
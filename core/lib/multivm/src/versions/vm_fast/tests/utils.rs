@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, fs};
 
 use ethabi::Contract;
 use once_cell::sync::Lazy;
@@ -10,17 +10,43 @@ use zksync_types::{
     utils::storage_key_for_standard_token_balance, AccountTreeId, Address, StorageKey, H160, H256,
     U256,
 };
-use zksync_utils::{bytecode::hash_bytecode, bytes_to_be_words, h256_to_u256, u256_to_h256};
+use zksync_utils::{
+    bytecode::hash_bytecode, bytes_to_be_words, h256_to_u256, u256_to_h256,
+    workspace_dir_or_current_dir,
+};
+
+use crate::interface::{storage::ReadStorage, ExecutionResult, Halt};
 
-use crate::interface::storage::ReadStorage;
+const TEST_BOOTLOADERS_DIR: &str = "contracts/system-contracts/bootloader/tests/artifacts";
 
 pub(crate) static BASE_SYSTEM_CONTRACTS: Lazy<BaseSystemContracts> =
     Lazy::new(BaseSystemContracts::load_from_disk);
 
-pub(crate) fn verify_required_memory(state: &State, required_values: Vec<(U256, HeapId, u32)>) {
-    for (required_value, memory_page, cell) in required_values {
-        let current_value = state.heaps[memory_page].read_u256(cell * 32);
-        assert_eq!(current_value, required_value);
+pub(crate) fn verify_required_memory(state: &State, required_values: &[(U256, HeapId, u32)]) {
+    let mismatches: Vec<_> = required_values
+        .iter()
+        .filter_map(|&(required_value, memory_page, cell)| {
+            let current_value = state.heaps[memory_page].read_u256(cell * 32);
+            (current_value != required_value).then(|| {
+                format!(
+                    "heap {memory_page:?}, cell {cell}: expected {required_value:#x}, got {current_value:#x}"
+                )
+            })
+        })
+        .collect();
+    assert!(
+        mismatches.is_empty(),
+        "Unexpected heap contents:\n{}",
+        mismatches.join("\n")
+    );
+}
+
+/// Asserts that execution halted with the `expected` reason, printing the full result otherwise.
+#[track_caller]
+pub(crate) fn assert_halt(result: &ExecutionResult, expected: Halt) {
+    match result {
+        ExecutionResult::Halt { reason } if *reason == expected => {}
+        _ => panic!("expected execution to halt with {expected:?}, got {result:?}"),
     }
 }
 
@@ -60,17 +86,35 @@ pub(crate) fn read_test_contract() -> Vec<u8> {
     read_bytecode("etc/contracts-test-data/artifacts-zk/contracts/counter/counter.sol/Counter.json")
 }
 
-pub(crate) fn get_bootloader(test: &str) -> SystemContractCode {
-    let bootloader_code = read_zbin_bytecode(format!(
-        "contracts/system-contracts/bootloader/tests/artifacts/{}.yul.zbin",
-        test
-    ));
+/// Returns the sorted names of the test bootloaders found in the compiled artifacts directory.
+pub(crate) fn available_bootloaders() -> Vec<String> {
+    let artifacts_dir = workspace_dir_or_current_dir().join(TEST_BOOTLOADERS_DIR);
+    let Ok(entries) = fs::read_dir(artifacts_dir) else {
+        return vec![];
+    };
+    let mut names: Vec<_> = entries
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            file_name.strip_suffix(".yul.zbin").map(str::to_owned)
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+pub(crate) fn get_bootloader(test: &str) -> anyhow::Result<SystemContractCode> {
+    let available = available_bootloaders();
+    if !available.iter().any(|name| name == test) {
+        anyhow::bail!("unknown test bootloader `{test}`; available bootloaders: {available:?}");
+    }
+
+    let bootloader_code = read_zbin_bytecode(format!("{TEST_BOOTLOADERS_DIR}/{test}.yul.zbin"));
 
     let bootloader_hash = hash_bytecode(&bootloader_code);
-    SystemContractCode {
+    Ok(SystemContractCode {
         code: bytes_to_be_words(bootloader_code),
         hash: bootloader_hash,
-    }
+    })
 }
 
 pub(crate) fn read_error_contract() -> Vec<u8> {
@@ -0,0 +1,23 @@
+use std::process::{Command, Stdio};
+
+/// Opens the given URL in the default browser of the host system.
+pub fn open(url: &str) -> anyhow::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    let status = command
+        .arg(url)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+    if !status.success() {
+        anyhow::bail!("Browser launcher exited with {status}");
+    }
+    Ok(())
+}
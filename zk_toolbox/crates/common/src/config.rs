@@ -1,5 +1,7 @@
 use once_cell::sync::OnceCell;
 
+use crate::logger::LogLevel;
+
 static CONFIG: OnceCell<GlobalConfig> = OnceCell::new();
 
 pub fn init_global_config(config: GlobalConfig) {
@@ -12,9 +14,15 @@ pub fn global_config() -> &'static GlobalConfig {
     CONFIG.get().expect("GlobalConfig not initialized")
 }
 
+/// Returns the global config if it was already initialized.
+pub(crate) fn try_global_config() -> Option<&'static GlobalConfig> {
+    CONFIG.get()
+}
+
 #[derive(Debug)]
 pub struct GlobalConfig {
     pub verbose: bool,
+    pub log_level: LogLevel,
     pub chain_name: Option<String>,
     pub ignore_prerequisites: bool,
 }
@@ -1,8 +1,20 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, thread, time::Duration};
 
 use xshell::{cmd, Shell};
 
-use crate::cmd::Cmd;
+use crate::{cmd::Cmd, logger};
+
+/// Fragments of `docker pull` errors caused by the network or the registry, not by the image itself.
+const TRANSIENT_PULL_ERRORS: [&str; 7] = [
+    "timeout",
+    "connection reset",
+    "connection refused",
+    "tls handshake",
+    "unexpected eof",
+    "toomanyrequests",
+    "service unavailable",
+];
+const PULL_RETRY_DELAY: Duration = Duration::from_secs(3);
 
 pub fn up(shell: &Shell, docker_compose_file: &str) -> anyhow::Result<()> {
     Ok(Cmd::new(cmd!(shell, "docker compose -f {docker_compose_file} up -d")).run()?)
@@ -12,11 +24,118 @@ pub fn down(shell: &Shell, docker_compose_file: &str) -> anyhow::Result<()> {
     Ok(Cmd::new(cmd!(shell, "docker compose -f {docker_compose_file} down")).run()?)
 }
 
+/// Returns the names of the running containers that publish the given host port.
+pub fn get_containers_by_published_port(shell: &Shell, port: u16) -> anyhow::Result<Vec<String>> {
+    get_containers(shell, &[format!("publish={port}")])
+}
+
+/// Returns the names of the running containers with the given `key=value` label
+/// that publish the given host port.
+pub fn get_labeled_containers_by_published_port(
+    shell: &Shell,
+    label: &str,
+    port: u16,
+) -> anyhow::Result<Vec<String>> {
+    get_containers(
+        shell,
+        &[format!("label={label}"), format!("publish={port}")],
+    )
+}
+
+fn get_containers(shell: &Shell, filters: &[String]) -> anyhow::Result<Vec<String>> {
+    let filters = filters
+        .iter()
+        .flat_map(|filter| ["--filter", filter.as_str()]);
+    let format = "{{.Names}}";
+    let output =
+        Cmd::new(cmd!(shell, "docker ps {filters...} --format {format}")).run_with_output()?;
+    if !output.status.success() {
+        anyhow::bail!("Failed to list docker containers");
+    }
+    let names = String::from_utf8(output.stdout)?;
+    Ok(names.lines().map(|name| name.to_string()).collect())
+}
+
+/// Checks whether any running container publishes the given host port.
+pub fn is_port_published(shell: &Shell, port: u16) -> anyhow::Result<bool> {
+    Ok(!get_containers_by_published_port(shell, port)?.is_empty())
+}
+
+pub fn restart(shell: &Shell, containers: &[String]) -> anyhow::Result<()> {
+    Ok(Cmd::new(cmd!(shell, "docker restart {containers...}")).run()?)
+}
+
+/// Returns the platforms (`os/architecture`) a multi-platform image is published for.
+pub fn get_image_platforms(shell: &Shell, docker_image: &str) -> anyhow::Result<Vec<String>> {
+    let output =
+        Cmd::new(cmd!(shell, "docker manifest inspect {docker_image}")).run_with_output()?;
+    if !output.status.success() {
+        anyhow::bail!("Failed to inspect the manifest of {docker_image}");
+    }
+    let manifest: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    // Single-platform images don't have a manifest list
+    let Some(manifests) = manifest["manifests"].as_array() else {
+        return Ok(vec![]);
+    };
+    Ok(manifests
+        .iter()
+        .filter_map(|manifest| {
+            let platform = &manifest["platform"];
+            Some(format!(
+                "{}/{}",
+                platform["os"].as_str()?,
+                platform["architecture"].as_str()?
+            ))
+        })
+        .collect())
+}
+
+/// Checks whether the image is already present in the local image store.
+pub fn image_exists(shell: &Shell, docker_image: &str) -> anyhow::Result<bool> {
+    let output = Cmd::new(cmd!(shell, "docker image inspect {docker_image}")).run_with_output()?;
+    Ok(output.status.success())
+}
+
+/// Pulls the image, retrying up to `retries` times when the failure looks transient.
+pub fn pull(shell: &Shell, docker_image: &str, platform: &str, retries: u32) -> anyhow::Result<()> {
+    let mut attempt = 0;
+    loop {
+        // The status is checked here, so that failed attempts aren't reported as errors
+        let output = Cmd::new(cmd!(
+            shell,
+            "docker pull --platform {platform} {docker_image}"
+        ))
+        .run_with_output()?;
+        if output.status.success() {
+            return Ok(());
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if attempt >= retries || !is_transient_pull_error(&stderr) {
+            anyhow::bail!("Failed to pull {docker_image}: {}", stderr.trim());
+        }
+        attempt += 1;
+        logger::warn(format!(
+            "Failed to pull {docker_image}, retrying ({attempt}/{retries})"
+        ));
+        thread::sleep(PULL_RETRY_DELAY);
+    }
+}
+
+fn is_transient_pull_error(stderr: &str) -> bool {
+    let stderr = stderr.to_lowercase();
+    TRANSIENT_PULL_ERRORS
+        .iter()
+        .any(|fragment| stderr.contains(fragment))
+}
+
 pub fn run(
     shell: &Shell,
     docker_image: &str,
     docker_args: HashMap<String, String>,
 ) -> anyhow::Result<()> {
+    // Sort the arguments so that the logged command is predictable
+    let mut docker_args: Vec<_> = docker_args.into_iter().collect();
+    docker_args.sort();
     let mut args = vec![];
     for (key, value) in docker_args.iter() {
         args.push(key);
@@ -24,3 +143,36 @@ pub fn run(
     }
     Ok(Cmd::new(cmd!(shell, "docker run {args...} {docker_image}")).run()?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_transient_pull_error() {
+        let cases = [
+            (
+                "Get \"https://registry-1.docker.io/v2/\": net/http: TLS handshake timeout",
+                true,
+            ),
+            (
+                "read tcp 172.17.0.1:443: read: connection reset by peer",
+                true,
+            ),
+            (
+                "toomanyrequests: You have reached your pull rate limit",
+                true,
+            ),
+            (
+                "received unexpected HTTP status: 503 Service Unavailable",
+                true,
+            ),
+            ("manifest for foo:latest not found: manifest unknown", false),
+            ("dial tcp: lookup registry-1.docker.io: no such host", false),
+            ("", false),
+        ];
+        for (stderr, expected) in cases {
+            assert_eq!(is_transient_pull_error(stderr), expected, "{stderr:?}");
+        }
+    }
+}
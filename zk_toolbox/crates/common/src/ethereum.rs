@@ -81,6 +81,12 @@ pub async fn get_token_info(token_address: Address, rpc_url: String) -> anyhow::
     })
 }
 
+pub async fn get_chain_id(rpc_url: String) -> anyhow::Result<u64> {
+    let provider = Provider::<Http>::try_from(rpc_url)?;
+    let chain_id = provider.get_chainid().await?;
+    Ok(chain_id.as_u64())
+}
+
 pub async fn mint_token(
     main_wallet: Wallet,
     token_address: Address,
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use serde::{de::DeserializeOwned, Serialize};
 use xshell::Shell;
@@ -41,8 +41,7 @@ pub fn save_yaml_file(
         comment.to_string(),
         serde_yaml::to_string(&content)?
     );
-    shell.write_file(file_path, data)?;
-    Ok(())
+    write_file_atomically(shell, file_path, data)
 }
 
 pub fn save_toml_file(
@@ -52,8 +51,7 @@ pub fn save_toml_file(
     comment: impl ToString,
 ) -> anyhow::Result<()> {
     let data = format!("{}{}", comment.to_string(), toml::to_string(&content)?);
-    shell.write_file(file_path, data)?;
-    Ok(())
+    write_file_atomically(shell, file_path, data)
 }
 
 pub fn save_json_file(
@@ -62,6 +60,42 @@ pub fn save_json_file(
     content: impl Serialize,
 ) -> anyhow::Result<()> {
     let data = serde_json::to_string_pretty(&content)?;
-    shell.write_file(file_path, data)?;
+    write_file_atomically(shell, file_path, data)
+}
+
+/// Writes the content to a temporary file next to the target and renames it into place,
+/// so an interrupted write never leaves a truncated file behind.
+pub fn write_file_atomically(
+    shell: &Shell,
+    file_path: impl AsRef<Path>,
+    content: impl AsRef<[u8]>,
+) -> anyhow::Result<()> {
+    // `std::fs` resolves relative paths against the process directory, not the shell one
+    let file_path = shell.current_dir().join(file_path);
+    let mut tmp_path = file_path.clone().into_os_string();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    shell.write_file(&tmp_path, content)?;
+    std::fs::rename(&tmp_path, &file_path)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_file_atomically() {
+        let shell = Shell::new().unwrap();
+        let dir = shell.create_temp_dir().unwrap();
+        let file_path = dir.path().join("general.yaml");
+        shell.write_file(&file_path, "old").unwrap();
+
+        write_file_atomically(&shell, &file_path, "new").unwrap();
+
+        assert_eq!(shell.read_file(&file_path).unwrap(), "new");
+        assert!(!dir.path().join("general.yaml.tmp").exists());
+        assert_eq!(shell.read_dir(dir.path()).unwrap(), vec![file_path]);
+    }
+}
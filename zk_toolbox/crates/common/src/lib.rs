@@ -2,6 +2,7 @@ mod prerequisites;
 mod prompt;
 mod term;
 
+pub mod browser;
 pub mod cmd;
 pub mod config;
 pub mod db;
@@ -13,6 +14,8 @@ pub mod git;
 pub mod server;
 pub mod wallets;
 
-pub use prerequisites::{check_general_prerequisites, check_prover_prequisites};
+pub use prerequisites::{
+    check_docker_daemon_prerequisites, check_general_prerequisites, check_prover_prequisites,
+};
 pub use prompt::{init_prompt_theme, Prompt, PromptConfirm, PromptSelect};
 pub use term::{error, logger, spinner};
@@ -25,6 +25,11 @@ const PREREQUISITES: [Prerequisite; 5] = [
     },
 ];
 
+const DOCKER_DAEMON_PREREQUISITE: Prerequisite = Prerequisite {
+    name: "running docker daemon",
+    download_link: "https://docs.docker.com/config/daemon/start/",
+};
+
 const DOCKER_COMPOSE_PREREQUISITE: Prerequisite = Prerequisite {
     name: "docker compose",
     download_link: "https://docs.docker.com/compose/install/",
@@ -66,6 +71,14 @@ pub fn check_prover_prequisites(shell: &Shell) {
     check_prerequisites(shell, &PROVER_PREREQUISITES, false);
 }
 
+/// Checks that the docker daemon is running. The docker binary itself
+/// is covered by the general prerequisites.
+pub fn check_docker_daemon_prerequisites(shell: &Shell) {
+    if !check_docker_daemon_prerequisite(shell) {
+        fail_on_missing_prerequisites(&[&DOCKER_DAEMON_PREREQUISITE]);
+    }
+}
+
 fn check_prerequisites(shell: &Shell, prerequisites: &[Prerequisite], check_compose: bool) {
     let mut missing_prerequisites = vec![];
 
@@ -80,26 +93,32 @@ fn check_prerequisites(shell: &Shell, prerequisites: &[Prerequisite], check_comp
     }
 
     if !missing_prerequisites.is_empty() {
-        logger::error("Prerequisite check has failed");
-        logger::error_note(
-            "The following prerequisites are missing",
-            &missing_prerequisites
-                .iter()
-                .map(|prerequisite| {
-                    format!("- {} ({})", prerequisite.name, prerequisite.download_link)
-                })
-                .collect::<Vec<_>>()
-                .join("\n"),
-        );
-        logger::outro("Failed");
-        std::process::exit(1);
+        fail_on_missing_prerequisites(&missing_prerequisites);
     }
 }
 
+fn fail_on_missing_prerequisites(missing_prerequisites: &[&Prerequisite]) -> ! {
+    logger::error("Prerequisite check has failed");
+    logger::error_note(
+        "The following prerequisites are missing",
+        &missing_prerequisites
+            .iter()
+            .map(|prerequisite| format!("- {} ({})", prerequisite.name, prerequisite.download_link))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+    logger::outro("Failed");
+    std::process::exit(1);
+}
+
 fn check_prerequisite(shell: &Shell, name: &str) -> bool {
     Cmd::new(cmd!(shell, "which {name}")).run().is_ok()
 }
 
+fn check_docker_daemon_prerequisite(shell: &Shell) -> bool {
+    Cmd::new(cmd!(shell, "docker info")).run().is_ok()
+}
+
 fn check_docker_compose_prerequisite(shell: &Shell) -> bool {
     Cmd::new(cmd!(shell, "docker compose version"))
         .run()
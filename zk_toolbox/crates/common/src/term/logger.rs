@@ -4,10 +4,39 @@ use cliclack::{intro as cliclak_intro, log, outro as cliclak_outro, Theme, Theme
 use console::{style, Emoji, Term};
 use serde::Serialize;
 
-use crate::prompt::CliclackTheme;
+use crate::{config::try_global_config, prompt::CliclackTheme};
 
 const S_BAR: Emoji = Emoji("│", "|");
 
+/// Verbosity of the logger output, ordered from the least to the most verbose.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// Only warnings, errors and results are printed.
+    Quiet,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// Maps the number of `-v` flags to a log level.
+    pub fn from_verbosity(verbosity: u8) -> Self {
+        match verbosity {
+            0 => LogLevel::Info,
+            1 => LogLevel::Debug,
+            _ => LogLevel::Trace,
+        }
+    }
+}
+
+/// Checks whether messages of the given level should be printed.
+/// Everything up to `Info` is printed before the global config is initialized.
+pub fn is_enabled(level: LogLevel) -> bool {
+    let current = try_global_config().map_or(LogLevel::default(), |config| config.log_level);
+    level <= current
+}
+
 fn term_write(msg: impl Display) {
     let msg = &format!("{}", msg);
     Term::stderr().write_str(msg).unwrap();
@@ -22,10 +51,39 @@ pub fn outro(msg: impl Display) {
 }
 
 pub fn info(msg: impl Display) {
+    if is_enabled(LogLevel::Info) {
+        log::info(msg).unwrap();
+    }
+}
+
+/// Prints the message even in quiet mode, for output that scripts rely on.
+pub fn info_always(msg: impl Display) {
     log::info(msg).unwrap();
 }
 
+/// Prints the message only when debug output is enabled with `-v`.
 pub fn debug(msg: impl Display) {
+    if is_enabled(LogLevel::Debug) {
+        write_debug(msg);
+    }
+}
+
+/// Prints the message only when trace output is enabled with `-vv`.
+pub fn trace(msg: impl Display) {
+    if is_enabled(LogLevel::Trace) {
+        write_debug(msg);
+    }
+}
+
+/// Prints a command that is about to be run. Unlike `debug`, it is also
+/// used without `-v` for commands that are forced to show their output.
+pub fn command(msg: impl Display) {
+    if is_enabled(LogLevel::Info) {
+        write_debug(msg);
+    }
+}
+
+fn write_debug(msg: impl Display) {
     let msg = &format!("{}", msg);
     let log = CliclackTheme.format_log(msg, style("⚙").dim().to_string().as_str());
     Term::stderr().write_str(&log).unwrap();
@@ -44,7 +102,9 @@ pub fn success(msg: impl Display) {
 }
 
 pub fn step(msg: impl Display) {
-    log::step(msg).unwrap();
+    if is_enabled(LogLevel::Info) {
+        log::step(msg).unwrap();
+    }
 }
 
 pub fn raw(msg: impl Display) {
@@ -116,3 +176,17 @@ pub fn new_line() {
         CliclackTheme.bar_color(&ThemeState::Submit).apply_to(S_BAR)
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_level_from_verbosity() {
+        assert_eq!(LogLevel::from_verbosity(0), LogLevel::Info);
+        assert_eq!(LogLevel::from_verbosity(1), LogLevel::Debug);
+        assert_eq!(LogLevel::from_verbosity(2), LogLevel::Trace);
+        assert_eq!(LogLevel::from_verbosity(5), LogLevel::Trace);
+        assert!(LogLevel::Quiet < LogLevel::Info);
+    }
+}
@@ -5,6 +5,7 @@ use std::{
 
 use serde::{Deserialize, Serialize, Serializer};
 use types::{BaseToken, L1BatchCommitmentMode, L1Network, ProverMode, WalletCreation};
+use url::Url;
 use xshell::Shell;
 use zksync_basic_types::L2ChainId;
 
@@ -37,6 +38,10 @@ pub struct ChainConfigInternal {
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
     pub base_token: BaseToken,
     pub wallet_creation: WalletCreation,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub l1_fallback_rpc_urls: Vec<Url>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_public_rpc_url: Option<Url>,
 }
 
 /// Chain configuration file. This file is created in the chain
@@ -55,6 +60,10 @@ pub struct ChainConfig {
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
     pub base_token: BaseToken,
     pub wallet_creation: WalletCreation,
+    /// L1 RPC URLs the portal falls back to when the L1 RPC URL from the secrets is unavailable
+    pub l1_fallback_rpc_urls: Vec<Url>,
+    /// L1 RPC URL exposed to the portal users instead of the possibly private L1 RPC URLs
+    pub l1_public_rpc_url: Option<Url>,
     pub shell: OnceCell<Shell>,
 }
 
@@ -150,6 +159,8 @@ impl ChainConfig {
             l1_batch_commit_data_generator_mode: self.l1_batch_commit_data_generator_mode,
             base_token: self.base_token.clone(),
             wallet_creation: self.wallet_creation,
+            l1_fallback_rpc_urls: self.l1_fallback_rpc_urls.clone(),
+            l1_public_rpc_url: self.l1_public_rpc_url.clone(),
         }
     }
 }
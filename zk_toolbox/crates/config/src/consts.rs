@@ -31,6 +31,8 @@ pub const ERA_OBSERBAVILITY_DIR: &str = "era-observability";
 /// Era observability repo link
 pub const ERA_OBSERBAVILITY_GIT_REPO: &str = "https://github.com/matter-labs/era-observability";
 pub(crate) const LOCAL_CONFIGS_PATH: &str = "configs/";
+/// Env variable overriding the local configs path
+pub(crate) const LOCAL_CONFIGS_PATH_ENV: &str = "ZK_TOOLBOX_LOCAL_CONFIGS_PATH";
 pub(crate) const LOCAL_DB_PATH: &str = "db/";
 
 /// Name of portal config file
@@ -6,7 +6,7 @@ use std::{
 use common::logger;
 use serde::{Deserialize, Serialize, Serializer};
 use thiserror::Error;
-use types::{L1Network, ProverMode, WalletCreation};
+use types::{L1Network, ProverMode, TokenInfo, WalletCreation};
 use xshell::Shell;
 use zksync_basic_types::L2ChainId;
 
@@ -39,6 +39,8 @@ struct EcosystemConfigInternal {
     pub era_chain_id: L2ChainId,
     pub prover_version: ProverMode,
     pub wallet_creation: WalletCreation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_native_currency: Option<TokenInfo>,
 }
 
 /// Ecosystem configuration file. This file is created in the chain
@@ -55,6 +57,8 @@ pub struct EcosystemConfig {
     pub era_chain_id: L2ChainId,
     pub prover_version: ProverMode,
     pub wallet_creation: WalletCreation,
+    /// Native currency of the L1 network, ETH if not set
+    pub l1_native_currency: Option<TokenInfo>,
     pub shell: OnceCell<Shell>,
 }
 
@@ -85,6 +89,7 @@ impl ReadConfig for EcosystemConfig {
             era_chain_id: config.era_chain_id,
             prover_version: config.prover_version,
             wallet_creation: config.wallet_creation,
+            l1_native_currency: config.l1_native_currency,
             shell: Default::default(),
         })
     }
@@ -161,6 +166,8 @@ impl EcosystemConfig {
             base_token: config.base_token,
             rocks_db_path: config.rocks_db_path,
             wallet_creation: config.wallet_creation,
+            l1_fallback_rpc_urls: config.l1_fallback_rpc_urls,
+            l1_public_rpc_url: config.l1_public_rpc_url,
             shell: self.get_shell().clone().into(),
         })
     }
@@ -244,6 +251,7 @@ impl EcosystemConfig {
             era_chain_id: self.era_chain_id,
             prover_version: self.prover_version,
             wallet_creation: self.wallet_creation,
+            l1_native_currency: self.l1_native_currency.clone(),
         }
     }
 }
@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use common::files::write_file_atomically;
 use xshell::Shell;
 pub use zksync_config::configs::en_config::ENConfig;
 use zksync_protobuf_config::{decode_yaml_repr, encode_yaml_repr};
@@ -16,7 +17,7 @@ impl FileConfigWithDefaultName for ENConfig {
 impl SaveConfig for ENConfig {
     fn save(&self, shell: &Shell, path: impl AsRef<Path>) -> anyhow::Result<()> {
         let bytes = encode_yaml_repr::<zksync_protobuf_config::proto::en::ExternalNode>(self)?;
-        Ok(shell.write_file(path, bytes)?)
+        write_file_atomically(shell, path, bytes)
     }
 }
 
@@ -1,12 +1,61 @@
-use std::path::{Path, PathBuf};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
 
 use xshell::Shell;
 
-use crate::consts::LOCAL_CONFIGS_PATH;
+use crate::consts::{LOCAL_CONFIGS_PATH, LOCAL_CONFIGS_PATH_ENV};
+
+/// Returns the local configs directory under the base path.
+/// It can be relocated with the `ZK_TOOLBOX_LOCAL_CONFIGS_PATH` env variable.
+pub fn local_configs_path(base_path: impl AsRef<Path>) -> anyhow::Result<PathBuf> {
+    resolve_local_configs_path(
+        base_path.as_ref(),
+        std::env::var_os(LOCAL_CONFIGS_PATH_ENV).as_deref(),
+    )
+}
+
+/// The override has to be relative, since the ecosystem and every chain
+/// have their own configs directory and must not share one.
+fn resolve_local_configs_path(
+    base_path: &Path,
+    configs_path_override: Option<&OsStr>,
+) -> anyhow::Result<PathBuf> {
+    let configs_path = configs_path_override.map_or(Path::new(LOCAL_CONFIGS_PATH), Path::new);
+    if configs_path.is_absolute() {
+        anyhow::bail!(
+            "{LOCAL_CONFIGS_PATH_ENV} must be relative to the ecosystem or chain directory, got {}",
+            configs_path.display()
+        );
+    }
+    Ok(base_path.join(configs_path))
+}
 
 pub fn create_local_configs_dir(
     shell: &Shell,
     base_path: impl AsRef<Path>,
-) -> xshell::Result<PathBuf> {
-    shell.create_dir(base_path.as_ref().join(LOCAL_CONFIGS_PATH))
+) -> anyhow::Result<PathBuf> {
+    Ok(shell.create_dir(local_configs_path(base_path)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_local_configs_path() {
+        let base_path = Path::new("/ecosystem/chains/era");
+        assert_eq!(
+            resolve_local_configs_path(base_path, None).unwrap(),
+            base_path.join(LOCAL_CONFIGS_PATH)
+        );
+        assert_eq!(
+            resolve_local_configs_path(base_path, Some(OsStr::new("etc/configs"))).unwrap(),
+            base_path.join("etc/configs")
+        );
+        assert!(
+            resolve_local_configs_path(base_path, Some(OsStr::new("/shared/configs"))).is_err()
+        );
+    }
 }
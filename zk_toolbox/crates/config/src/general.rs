@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use anyhow::Context;
+use common::files::write_file_atomically;
 use url::Url;
 use xshell::Shell;
 pub use zksync_config::configs::GeneralConfig;
@@ -106,7 +107,7 @@ impl SaveConfig for GeneralConfig {
     fn save(&self, shell: &Shell, path: impl AsRef<Path>) -> anyhow::Result<()> {
         let bytes =
             encode_yaml_repr::<zksync_protobuf_config::proto::general::GeneralConfig>(self)?;
-        Ok(shell.write_file(path, bytes)?)
+        write_file_atomically(shell, path, bytes)
     }
 }
 
@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use common::files::write_file_atomically;
 use xshell::Shell;
 use zksync_basic_types::L1ChainId;
 pub use zksync_config::GenesisConfig;
@@ -25,7 +26,7 @@ impl FileConfigWithDefaultName for GenesisConfig {
 impl SaveConfig for GenesisConfig {
     fn save(&self, shell: &Shell, path: impl AsRef<Path>) -> anyhow::Result<()> {
         let bytes = encode_yaml_repr::<zksync_protobuf_config::proto::genesis::Genesis>(self)?;
-        Ok(shell.write_file(path, bytes)?)
+        write_file_atomically(shell, path, bytes)
     }
 }
 
@@ -1,11 +1,12 @@
 use std::path::{Path, PathBuf};
 
+use common::files::write_file_atomically;
 use serde::{Deserialize, Serialize};
 use types::TokenInfo;
 use xshell::Shell;
 
 use crate::{
-    consts::{LOCAL_CONFIGS_PATH, PORTAL_CONFIG_FILE},
+    consts::PORTAL_CONFIG_FILE,
     traits::{FileConfigWithDefaultName, ReadConfig, SaveConfig},
 };
 
@@ -82,10 +83,9 @@ pub struct TokenConfig {
 }
 
 impl PortalRuntimeConfig {
-    pub fn get_config_path(ecosystem_base_path: &Path) -> PathBuf {
-        ecosystem_base_path
-            .join(LOCAL_CONFIGS_PATH)
-            .join(PORTAL_CONFIG_FILE)
+    /// Returns the path of the portal config inside the ecosystem configs directory.
+    pub fn get_config_path(ecosystem_configs_path: &Path) -> PathBuf {
+        ecosystem_configs_path.join(PORTAL_CONFIG_FILE)
     }
 }
 
@@ -102,7 +102,7 @@ impl SaveConfig for PortalRuntimeConfig {
         // This file will be mounted to the Docker image when it runs.
         let json = serde_json::to_string_pretty(&self)?;
         let config_js_content = format!("window['##runtimeConfig'] = {};", json);
-        Ok(shell.write_file(path, config_js_content.as_bytes())?)
+        write_file_atomically(shell, path, config_js_content)
     }
 }
 
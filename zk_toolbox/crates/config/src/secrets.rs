@@ -1,7 +1,7 @@
 use std::{path::Path, str::FromStr};
 
 use anyhow::Context;
-use common::db::DatabaseConfig;
+use common::{db::DatabaseConfig, files::write_file_atomically};
 use xshell::Shell;
 use zksync_basic_types::url::SensitiveUrl;
 pub use zksync_config::configs::Secrets as SecretsConfig;
@@ -54,7 +54,7 @@ impl FileConfigWithDefaultName for SecretsConfig {
 impl SaveConfig for SecretsConfig {
     fn save(&self, shell: &Shell, path: impl AsRef<Path>) -> anyhow::Result<()> {
         let bytes = encode_yaml_repr::<zksync_protobuf_config::proto::secrets::Secrets>(self)?;
-        Ok(shell.write_file(path, bytes)?)
+        write_file_atomically(shell, path, bytes)
     }
 }
 
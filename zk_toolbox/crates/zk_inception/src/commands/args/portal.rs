@@ -1,12 +1,85 @@
-use clap::Parser;
+use std::{path::PathBuf, time::Duration};
+
+use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Parser)]
 pub struct PortalArgs {
+    #[command(subcommand)]
+    pub command: Option<PortalCommands>,
     #[clap(
         long,
         default_value = "3030",
+        global = true,
         help = "The port number for the portal app"
     )]
     pub port: u16,
+    #[clap(
+        long,
+        global = true,
+        help = "Fail instead of warning when a chain RPC URL is unreachable"
+    )]
+    pub strict: bool,
+    #[clap(
+        long,
+        global = true,
+        help = "Path to a hand-maintained portal config file to use instead of generating one"
+    )]
+    pub config: Option<PathBuf>,
+    #[clap(
+        long,
+        global = true,
+        help = "Open the portal in the browser once it is started"
+    )]
+    pub open: bool,
+    #[clap(
+        long,
+        default_value = "10",
+        global = true,
+        help = "Timeout in seconds for RPC calls made while preparing the portal"
+    )]
+    pub rpc_timeout: u64,
+    #[clap(
+        long,
+        default_value = "2",
+        global = true,
+        help = "How many times to retry pulling the portal image on transient network errors"
+    )]
+    pub up_retries: u32,
+    #[clap(
+        long,
+        global = true,
+        help = "Docker platform of the portal image, e.g. linux/arm64, detected from the host by default"
+    )]
+    pub platform: Option<String>,
+    #[clap(
+        long,
+        global = true,
+        value_delimiter = ',',
+        conflicts_with = "config",
+        help = "Comma-separated chains to include in the portal, all chains by default"
+    )]
+    pub chains: Option<Vec<String>>,
+}
+
+impl PortalArgs {
+    pub fn rpc_timeout(&self) -> Duration {
+        Duration::from_secs(self.rpc_timeout)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Subcommand)]
+pub enum PortalCommands {
+    /// Show whether the portal is running and its chains are reachable
+    Status,
+    /// Regenerate the portal config and restart the running portal container
+    Restart,
+    /// Print the portal runtime config without starting the portal
+    Config(PortalConfigArgs),
+}
+
+#[derive(Debug, Serialize, Deserialize, Parser)]
+pub struct PortalConfigArgs {
+    #[clap(long, help = "Print the config as JSON")]
+    pub json: bool,
 }
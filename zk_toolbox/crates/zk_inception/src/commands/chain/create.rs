@@ -77,6 +77,8 @@ pub(crate) fn create_chain_inner(
         l1_batch_commit_data_generator_mode: args.l1_batch_commit_data_generator_mode,
         base_token: args.base_token,
         wallet_creation: args.wallet_creation,
+        l1_fallback_rpc_urls: vec![],
+        l1_public_rpc_url: None,
         shell: OnceCell::from(shell.clone()),
     };
 
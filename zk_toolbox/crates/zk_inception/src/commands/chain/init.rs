@@ -27,7 +27,7 @@ use crate::{
             genesis::genesis,
             set_token_multiplier_setter::set_token_multiplier_setter,
         },
-        portal::create_and_save_portal_config,
+        portal::{create_and_save_portal_config, PortalConfigOptions},
     },
     consts::AMOUNT_FOR_DISTRIBUTION_TO_WALLETS,
     messages::{
@@ -149,7 +149,7 @@ pub async fn init(
         .await
         .context(MSG_GENESIS_DATABASE_ERR)?;
 
-    create_and_save_portal_config(ecosystem_config, shell)
+    create_and_save_portal_config(ecosystem_config, shell, &PortalConfigOptions::default())
         .await
         .context(MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR)?;
 
@@ -1,26 +1,115 @@
-use std::{collections::HashMap, path::Path};
+use std::{
+    collections::HashMap,
+    future::Future,
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpStream},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
 
-use anyhow::{anyhow, Context};
-use common::{docker, ethereum, logger};
+use anyhow::Context;
+use common::{
+    browser, check_docker_daemon_prerequisites, config::global_config, docker, ethereum, logger,
+};
 use config::{
     portal::*,
     traits::{ReadConfig, SaveConfig},
     ChainConfig, EcosystemConfig,
 };
 use ethers::types::Address;
-use types::{BaseToken, TokenInfo};
+use thiserror::Error;
+use types::TokenInfo;
 use xshell::Shell;
 
 use crate::{
-    commands::args::PortalArgs,
-    consts::{L2_BASE_TOKEN_ADDRESS, PORTAL_DOCKER_CONTAINER_PORT, PORTAL_DOCKER_IMAGE},
+    commands::args::{PortalArgs, PortalCommands},
+    consts::{
+        DOCKER_ECOSYSTEM_LABEL, L2_BASE_TOKEN_ADDRESS, PORTAL_CHECK_TIMEOUT,
+        PORTAL_DEFAULT_DOCKER_PLATFORM, PORTAL_DOCKER_CONTAINER_PORT, PORTAL_DOCKER_IMAGE,
+        PORTAL_OPEN_MAX_ATTEMPTS, PORTAL_RPC_TIMEOUT, PORTAL_TOKEN_INFO_ATTEMPTS,
+        PORTAL_TOKEN_INFO_RETRY_DELAY,
+    },
     messages::{
-        msg_portal_starting_on, MSG_PORTAL_CONFIG_IS_EMPTY_ERR,
-        MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR, MSG_PORTAL_FAILED_TO_RUN_DOCKER_ERR,
+        msg_portal_already_running, msg_portal_base_token_placeholder, msg_portal_base_token_retry,
+        msg_portal_chain_rpc_reachable, msg_portal_chain_rpc_unreachable,
+        msg_portal_container_not_running, msg_portal_container_running,
+        msg_portal_failed_to_create_chain_config, msg_portal_failed_to_load_chain,
+        msg_portal_failed_to_open_browser, msg_portal_not_running_starting,
+        msg_portal_platform_emulation, msg_portal_port_taken_by_other_container,
+        msg_portal_reachable, msg_portal_restarted_on, msg_portal_rpc_timeout,
+        msg_portal_runtime_config, msg_portal_starting_on, msg_portal_unknown_chain,
+        msg_portal_unreachable, msg_portal_using_cached_image, MSG_PORTAL_CONFIG_IS_EMPTY_ERR,
+        MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR, MSG_PORTAL_FAILED_TO_PULL_DOCKER_ERR,
+        MSG_PORTAL_FAILED_TO_READ_CONFIG_ERR, MSG_PORTAL_FAILED_TO_RESTART_DOCKER_ERR,
+        MSG_PORTAL_FAILED_TO_RUN_DOCKER_ERR, MSG_PORTAL_NO_VALID_CHAINS_ERR,
+        MSG_PORTAL_RUNTIME_CONFIG,
     },
+    utils::base_token::{resolve_base_token, validate_base_token_address},
 };
 
-async fn create_hyperchain_config(chain_config: &ChainConfig) -> anyhow::Result<HyperchainConfig> {
+/// Errors of the portal command that callers may want to tell apart.
+/// They are converted into `anyhow::Error` at the command boundary.
+#[derive(Error, Debug)]
+pub enum PortalError {
+    #[error("{}", MSG_PORTAL_NO_VALID_CHAINS_ERR)]
+    NoValidChains,
+    #[error("{}", MSG_PORTAL_CONFIG_IS_EMPTY_ERR)]
+    EmptyConfig,
+    #[error("{}", msg_portal_already_running(*.port))]
+    AlreadyRunning { port: u16 },
+    #[error("{}", msg_portal_port_taken_by_other_container(*.port))]
+    PortTakenByOtherContainer { port: u16 },
+    #[error("{}", msg_portal_unknown_chain(.chain, .available))]
+    UnknownChain {
+        chain: String,
+        available: Vec<String>,
+    },
+    #[error("{}", msg_portal_rpc_timeout(.operation, .rpc_url, .timeout))]
+    RpcTimeout {
+        operation: String,
+        rpc_url: String,
+        timeout: Duration,
+    },
+    #[error("{}", MSG_PORTAL_FAILED_TO_PULL_DOCKER_ERR)]
+    DockerPull { source: anyhow::Error },
+    #[error("{}", MSG_PORTAL_FAILED_TO_RUN_DOCKER_ERR)]
+    DockerRun { source: anyhow::Error },
+    #[error("{}", MSG_PORTAL_FAILED_TO_RESTART_DOCKER_ERR)]
+    DockerRestart { source: anyhow::Error },
+}
+
+/// Settings of the portal config generation that don't come from the ecosystem configs.
+#[derive(Debug, Clone)]
+pub struct PortalConfigOptions {
+    pub rpc_timeout: Duration,
+    /// Chains to include in the portal, all ecosystem chains if not set
+    pub chains: Option<Vec<String>>,
+}
+
+impl Default for PortalConfigOptions {
+    fn default() -> Self {
+        Self {
+            rpc_timeout: PORTAL_RPC_TIMEOUT,
+            chains: None,
+        }
+    }
+}
+
+impl From<&PortalArgs> for PortalConfigOptions {
+    fn from(args: &PortalArgs) -> Self {
+        Self {
+            rpc_timeout: args.rpc_timeout(),
+            chains: args.chains.clone(),
+        }
+    }
+}
+
+async fn create_hyperchain_config(
+    chain_config: &ChainConfig,
+    l1_native_currency: &TokenInfo,
+    options: &PortalConfigOptions,
+) -> anyhow::Result<HyperchainConfig> {
     // Get L2 RPC URL from general config
     let general_config = chain_config.get_general_config()?;
     let rpc_url = general_config
@@ -35,34 +124,36 @@ async fn create_hyperchain_config(chain_config: &ChainConfig) -> anyhow::Result<
         .as_ref()
         .map(|l1| l1.l1_rpc_url.expose_str())
         .context("l1")?;
-    // Build L1 network config
+    // Build L1 network config, the chain L1 RPC URL goes first
+    let l1_rpc_urls: Vec<String> = std::iter::once(l1_rpc_url.to_string())
+        .chain(
+            chain_config
+                .l1_fallback_rpc_urls
+                .iter()
+                .map(|url| url.to_string()),
+        )
+        .collect();
     let l1_network = Some(L1NetworkConfig {
         id: chain_config.l1_network.chain_id(),
         name: chain_config.l1_network.to_string(),
         network: chain_config.l1_network.to_string().to_lowercase(),
-        native_currency: TokenInfo::eth(),
+        native_currency: l1_native_currency.clone(),
         rpc_urls: RpcUrls {
-            default: RpcUrlConfig {
-                http: vec![l1_rpc_url.to_string()],
-            },
             public: RpcUrlConfig {
-                http: vec![l1_rpc_url.to_string()],
+                http: chain_config
+                    .l1_public_rpc_url
+                    .as_ref()
+                    .map_or_else(|| l1_rpc_urls.clone(), |url| vec![url.to_string()]),
             },
+            default: RpcUrlConfig { http: l1_rpc_urls },
         },
     });
     // Base token:
-    let (base_token_addr, base_token_info) = if chain_config.base_token == BaseToken::eth() {
-        (format!("{:?}", Address::zero()), TokenInfo::eth())
-    } else {
-        (
-            format!("{:?}", chain_config.base_token.address),
-            ethereum::get_token_info(chain_config.base_token.address, l1_rpc_url.to_string())
-                .await?,
-        )
-    };
+    let (base_token_addr, base_token_info) =
+        resolve_base_token_with_retries(chain_config, l1_rpc_url, options.rpc_timeout).await?;
     let tokens = vec![TokenConfig {
         address: L2_BASE_TOKEN_ADDRESS.to_string(),
-        l1_address: Some(base_token_addr.to_string()),
+        l1_address: Some(format!("{:?}", base_token_addr)),
         symbol: base_token_info.symbol,
         decimals: base_token_info.decimals,
         name: Some(base_token_info.name.to_string()),
@@ -83,13 +174,64 @@ async fn create_hyperchain_config(chain_config: &ChainConfig) -> anyhow::Result<
     })
 }
 
+/// Unreachable token contracts shouldn't hide the chain from the portal,
+/// so after the last failed attempt a placeholder token is used instead.
+/// An invalid base token address is a config error and is returned right away.
+async fn resolve_base_token_with_retries(
+    chain_config: &ChainConfig,
+    l1_rpc_url: &str,
+    rpc_timeout: Duration,
+) -> anyhow::Result<(Address, TokenInfo)> {
+    validate_base_token_address(chain_config)?;
+    let mut attempt = 1;
+    loop {
+        let base_token = with_rpc_timeout(
+            rpc_timeout,
+            l1_rpc_url,
+            "get base token info",
+            resolve_base_token(chain_config, l1_rpc_url),
+        )
+        .await;
+        match base_token {
+            Ok(base_token) => return Ok(base_token),
+            Err(err) if attempt < PORTAL_TOKEN_INFO_ATTEMPTS => {
+                logger::debug(msg_portal_base_token_retry(
+                    &chain_config.name,
+                    attempt,
+                    &err,
+                ));
+                tokio::time::sleep(PORTAL_TOKEN_INFO_RETRY_DELAY).await;
+                attempt += 1;
+            }
+            Err(err) => {
+                logger::warn(msg_portal_base_token_placeholder(&chain_config.name, &err));
+                return Ok((chain_config.base_token.address, placeholder_token_info()));
+            }
+        }
+    }
+}
+
+fn placeholder_token_info() -> TokenInfo {
+    TokenInfo {
+        name: "Unknown token".to_string(),
+        symbol: "UNKNOWN".to_string(),
+        decimals: 18,
+    }
+}
+
 async fn create_hyperchains_config(
     chain_configs: &[ChainConfig],
+    l1_native_currency: &TokenInfo,
+    options: &PortalConfigOptions,
 ) -> anyhow::Result<HyperchainsConfig> {
     let mut hyperchain_configs = Vec::new();
     for chain_config in chain_configs {
-        if let Ok(config) = create_hyperchain_config(chain_config).await {
-            hyperchain_configs.push(config)
+        match create_hyperchain_config(chain_config, l1_native_currency, options).await {
+            Ok(config) => hyperchain_configs.push(config),
+            Err(err) => logger::warn(msg_portal_failed_to_create_chain_config(
+                &chain_config.name,
+                &err,
+            )),
         }
     }
     Ok(HyperchainsConfig(hyperchain_configs))
@@ -97,17 +239,24 @@ async fn create_hyperchains_config(
 
 pub async fn create_portal_config(
     ecosystem_config: &EcosystemConfig,
+    options: &PortalConfigOptions,
 ) -> anyhow::Result<PortalRuntimeConfig> {
-    let chains: Vec<String> = ecosystem_config.list_of_chains();
+    let chains = select_chains(ecosystem_config.list_of_chains(), options.chains.as_deref())?;
     let mut chain_configs = Vec::new();
     for chain in chains {
-        if let Some(chain_config) = ecosystem_config.load_chain(Some(chain.clone())) {
-            chain_configs.push(chain_config)
+        match ecosystem_config.load_chain(Some(chain.clone())) {
+            Some(chain_config) => chain_configs.push(chain_config),
+            None => logger::warn(msg_portal_failed_to_load_chain(&chain)),
         }
     }
-    let hyperchains_config = create_hyperchains_config(&chain_configs).await?;
+    let l1_native_currency = ecosystem_config
+        .l1_native_currency
+        .clone()
+        .unwrap_or_else(TokenInfo::eth);
+    let hyperchains_config =
+        create_hyperchains_config(&chain_configs, &l1_native_currency, options).await?;
     if hyperchains_config.is_empty() {
-        anyhow::bail!("Failed to create any valid hyperchain config")
+        return Err(PortalError::NoValidChains.into());
     }
     let runtime_config = PortalRuntimeConfig {
         node_type: "hyperchain".to_string(),
@@ -116,49 +265,449 @@ pub async fn create_portal_config(
     Ok(runtime_config)
 }
 
+/// Returns the selected chains without duplicates, or all available chains if none are selected.
+fn select_chains(
+    available: Vec<String>,
+    selected: Option<&[String]>,
+) -> Result<Vec<String>, PortalError> {
+    let Some(selected) = selected else {
+        return Ok(available);
+    };
+    if let Some(chain) = selected.iter().find(|chain| !available.contains(chain)) {
+        return Err(PortalError::UnknownChain {
+            chain: chain.clone(),
+            available,
+        });
+    }
+    let mut chains: Vec<String> = Vec::with_capacity(selected.len());
+    for chain in selected {
+        if !chains.contains(chain) {
+            chains.push(chain.clone());
+        }
+    }
+    Ok(chains)
+}
+
 pub async fn create_and_save_portal_config(
     ecosystem_config: &EcosystemConfig,
     shell: &Shell,
+    options: &PortalConfigOptions,
 ) -> anyhow::Result<PortalRuntimeConfig> {
-    let portal_config = create_portal_config(ecosystem_config).await?;
-    let config_path = PortalRuntimeConfig::get_config_path(&shell.current_dir());
+    let portal_config = create_portal_config(ecosystem_config, options).await?;
+    let config_path = get_config_path(shell, ecosystem_config, None);
     portal_config.save(shell, config_path)?;
     Ok(portal_config)
 }
 
 pub async fn run(shell: &Shell, args: PortalArgs) -> anyhow::Result<()> {
+    // Printing the config is the only subcommand that doesn't need docker
+    if !global_config().ignore_prerequisites
+        && !matches!(args.command, Some(PortalCommands::Config(_)))
+    {
+        check_docker_daemon_prerequisites(shell);
+    }
+    match args.command {
+        Some(PortalCommands::Status) => status(shell, &args).await,
+        Some(PortalCommands::Restart) => restart(shell, args).await,
+        Some(PortalCommands::Config(ref config_args)) => {
+            print_config(shell, &args, config_args.json).await
+        }
+        None => start(shell, args).await,
+    }
+}
+
+async fn start(shell: &Shell, args: PortalArgs) -> anyhow::Result<()> {
+    if docker::is_port_published(shell, args.port)? {
+        return Err(PortalError::AlreadyRunning { port: args.port }.into());
+    }
     let ecosystem_config: EcosystemConfig = EcosystemConfig::from_file(shell)?;
-    let config_path = PortalRuntimeConfig::get_config_path(&shell.current_dir());
+    let (config_path, portal_config) =
+        prepare_portal_config(shell, &args, &ecosystem_config).await?;
+    start_portal(
+        shell,
+        &args,
+        &ecosystem_config,
+        &config_path,
+        &portal_config,
+    )
+    .await
+}
+
+/// Returns the path of the portal config to mount together with its content.
+async fn prepare_portal_config(
+    shell: &Shell,
+    args: &PortalArgs,
+    ecosystem_config: &EcosystemConfig,
+) -> anyhow::Result<(PathBuf, PortalRuntimeConfig)> {
+    let config_path = get_config_path(shell, ecosystem_config, args.config.as_deref());
     logger::info(format!(
         "Using portal config file at {}",
         config_path.display()
     ));
 
-    let portal_config = match PortalRuntimeConfig::read(shell, &config_path) {
-        Ok(config) => config,
-        Err(_) => create_and_save_portal_config(&ecosystem_config, shell)
+    // A user-provided config is mounted as is, so it is only validated and never regenerated.
+    // Otherwise the config is rebuilt on every start, so that the generation flags always
+    // make it into the mounted file instead of a stale one written by `chain init`.
+    let portal_config = if args.config.is_some() {
+        PortalRuntimeConfig::read(shell, &config_path)
+            .context(MSG_PORTAL_FAILED_TO_READ_CONFIG_ERR)?
+    } else {
+        create_and_save_portal_config(ecosystem_config, shell, &PortalConfigOptions::from(args))
             .await
-            .context(MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR)?,
+            .context(MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR)?
     };
     if portal_config.hyperchains_config.is_empty() {
-        return Err(anyhow!(MSG_PORTAL_CONFIG_IS_EMPTY_ERR));
+        return Err(PortalError::EmptyConfig.into());
     }
+    logger::trace(msg_portal_runtime_config(&serde_json::to_string_pretty(
+        &portal_config,
+    )?));
+    Ok((config_path, portal_config))
+}
+
+async fn start_portal(
+    shell: &Shell,
+    args: &PortalArgs,
+    ecosystem_config: &EcosystemConfig,
+    config_path: &Path,
+    portal_config: &PortalRuntimeConfig,
+) -> anyhow::Result<()> {
+    check_chains_rpc(portal_config, args.strict, args.rpc_timeout()).await?;
+
+    logger::info_always(msg_portal_starting_on("127.0.0.1", args.port));
+    let platform = resolve_platform(shell, args.platform.as_deref());
+    pull_portal_image(shell, &platform, args.up_retries)?;
+    // Spawned only now so that the pull doesn't eat into the wait for the portal
+    if args.open {
+        open_portal_when_ready(args.port);
+    }
+    run_portal(
+        shell,
+        config_path,
+        args.port,
+        &platform,
+        &ecosystem_config.name,
+    )?;
+    Ok(())
+}
+
+async fn restart(shell: &Shell, args: PortalArgs) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    // Prepared once, so that it is reused if the portal has to be started instead
+    let (config_path, portal_config) =
+        prepare_portal_config(shell, &args, &ecosystem_config).await?;
+
+    // Only the portal of this ecosystem is restarted, not whatever else uses the port
+    let containers = docker::get_labeled_containers_by_published_port(
+        shell,
+        &portal_container_label(&ecosystem_config.name),
+        args.port,
+    )?;
+    if containers.is_empty() {
+        // Unlabeled containers, e.g. a portal started by an older version, are left alone
+        if docker::is_port_published(shell, args.port)? {
+            return Err(PortalError::PortTakenByOtherContainer { port: args.port }.into());
+        }
+        logger::info(msg_portal_not_running_starting(args.port));
+        return start_portal(
+            shell,
+            &args,
+            &ecosystem_config,
+            &config_path,
+            &portal_config,
+        )
+        .await;
+    }
+    // The config file is bind-mounted, so restarting the container picks up the new file
+    docker::restart(shell, &containers).map_err(|source| PortalError::DockerRestart { source })?;
+    logger::info_always(msg_portal_restarted_on("127.0.0.1", args.port));
+    Ok(())
+}
 
-    logger::info(msg_portal_starting_on("127.0.0.1", args.port));
-    run_portal(shell, &config_path, args.port)?;
+/// Shows the config the portal would be started with, generating it if no custom config is used.
+async fn print_config(shell: &Shell, args: &PortalArgs, json: bool) -> anyhow::Result<()> {
+    let portal_config = match args.config {
+        Some(ref path) => PortalRuntimeConfig::read(shell, shell.current_dir().join(path))
+            .context(MSG_PORTAL_FAILED_TO_READ_CONFIG_ERR)?,
+        None => {
+            let ecosystem_config = EcosystemConfig::from_file(shell)?;
+            create_portal_config(&ecosystem_config, &PortalConfigOptions::from(args))
+                .await
+                .context(MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR)?
+        }
+    };
+    if json {
+        // Printed to stdout so that it can be piped into other tools
+        println!("{}", serde_json::to_string_pretty(&portal_config)?);
+    } else {
+        logger::note(
+            MSG_PORTAL_RUNTIME_CONFIG,
+            logger::object_to_string(&portal_config),
+        );
+    }
     Ok(())
 }
 
-fn run_portal(shell: &Shell, config_file_path: &Path, port: u16) -> anyhow::Result<()> {
+/// Pulls the portal image, falling back to a cached one when the registry can't be reached.
+fn pull_portal_image(shell: &Shell, platform: &str, retries: u32) -> Result<(), PortalError> {
+    match docker::pull(shell, PORTAL_DOCKER_IMAGE, platform, retries) {
+        Ok(()) => Ok(()),
+        Err(err) if docker::image_exists(shell, PORTAL_DOCKER_IMAGE).unwrap_or(false) => {
+            logger::warn(msg_portal_using_cached_image(&err));
+            Ok(())
+        }
+        Err(source) => Err(PortalError::DockerPull { source }),
+    }
+}
+
+/// Picks the platform of the portal image, preferring a native one over amd64 emulation.
+fn resolve_platform(shell: &Shell, platform: Option<&str>) -> String {
+    if let Some(platform) = platform {
+        return platform.to_string();
+    }
+    let host_platform = match std::env::consts::ARCH {
+        "aarch64" => "linux/arm64",
+        _ => return PORTAL_DEFAULT_DOCKER_PLATFORM.to_string(),
+    };
+    match docker::get_image_platforms(shell, PORTAL_DOCKER_IMAGE) {
+        Ok(platforms) if platforms.iter().any(|platform| platform == host_platform) => {
+            host_platform.to_string()
+        }
+        _ => {
+            logger::warn(msg_portal_platform_emulation(
+                host_platform,
+                PORTAL_DEFAULT_DOCKER_PLATFORM,
+            ));
+            PORTAL_DEFAULT_DOCKER_PLATFORM.to_string()
+        }
+    }
+}
+
+fn run_portal(
+    shell: &Shell,
+    config_file_path: &Path,
+    port: u16,
+    platform: &str,
+    ecosystem_name: &str,
+) -> anyhow::Result<()> {
     let port_mapping = format!("{}:{}", port, PORTAL_DOCKER_CONTAINER_PORT);
     let volume_mapping = format!("{}:/usr/src/app/dist/config.js", config_file_path.display());
 
     let mut docker_args: HashMap<String, String> = HashMap::new();
-    docker_args.insert("--platform".to_string(), "linux/amd64".to_string());
+    docker_args.insert("--platform".to_string(), platform.to_string());
     docker_args.insert("-p".to_string(), port_mapping);
     docker_args.insert("-v".to_string(), volume_mapping);
+    docker_args.insert(
+        "--label".to_string(),
+        portal_container_label(ecosystem_name),
+    );
 
     docker::run(shell, PORTAL_DOCKER_IMAGE, docker_args)
-        .with_context(|| MSG_PORTAL_FAILED_TO_RUN_DOCKER_ERR)?;
+        .map_err(|source| PortalError::DockerRun { source })?;
+    Ok(())
+}
+
+/// Label of the portal container, used to tell it apart from other containers on the same port.
+fn portal_container_label(ecosystem_name: &str) -> String {
+    format!("{DOCKER_ECOSYSTEM_LABEL}={ecosystem_name}")
+}
+
+/// The portal container runs in the foreground, so the browser is opened from
+/// a background thread as soon as the portal port starts accepting connections.
+fn open_portal_when_ready(port: u16) {
+    thread::spawn(move || {
+        let url = format!("http://127.0.0.1:{port}");
+        let portal_addr = SocketAddr::from(([127, 0, 0, 1], port));
+        for _ in 0..PORTAL_OPEN_MAX_ATTEMPTS {
+            if TcpStream::connect_timeout(&portal_addr, PORTAL_CHECK_TIMEOUT).is_ok() {
+                if let Err(err) = browser::open(&url) {
+                    logger::warn(msg_portal_failed_to_open_browser(&url, err));
+                }
+                return;
+            }
+            thread::sleep(Duration::from_secs(1));
+        }
+    });
+}
+
+fn get_config_path(
+    shell: &Shell,
+    ecosystem_config: &EcosystemConfig,
+    custom_config: Option<&Path>,
+) -> PathBuf {
+    // Docker requires an absolute path for the volume mapping
+    match custom_config {
+        Some(path) => shell.current_dir().join(path),
+        None => PortalRuntimeConfig::get_config_path(
+            &shell.current_dir().join(&ecosystem_config.config),
+        ),
+    }
+}
+
+async fn status(shell: &Shell, args: &PortalArgs) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let port = args.port;
+    let containers = docker::get_labeled_containers_by_published_port(
+        shell,
+        &portal_container_label(&ecosystem_config.name),
+        port,
+    )?;
+    if containers.is_empty() {
+        logger::warn(msg_portal_container_not_running(port));
+    } else {
+        logger::info_always(msg_portal_container_running(&containers.join(", "), port));
+    }
+
+    match probe_portal(port) {
+        Ok(status) if status < 400 => {
+            logger::info_always(msg_portal_reachable("127.0.0.1", port, status))
+        }
+        Ok(status) => logger::warn(msg_portal_unreachable(
+            "127.0.0.1",
+            port,
+            format!("HTTP {status}"),
+        )),
+        Err(err) => logger::warn(msg_portal_unreachable("127.0.0.1", port, err)),
+    }
+
+    let config_path = get_config_path(shell, &ecosystem_config, args.config.as_deref());
+    let portal_config = PortalRuntimeConfig::read(shell, &config_path)
+        .context(MSG_PORTAL_FAILED_TO_READ_CONFIG_ERR)?;
+    for hyperchain in &portal_config.hyperchains_config.0 {
+        let network = &hyperchain.network;
+        match check_rpc_url(&network.rpc_url, args.rpc_timeout()).await {
+            Ok(chain_id) => logger::info_always(msg_portal_chain_rpc_reachable(
+                &network.name,
+                &network.rpc_url,
+                chain_id,
+            )),
+            Err(err) => logger::warn(msg_portal_chain_rpc_unreachable(
+                &network.name,
+                &network.rpc_url,
+                err,
+            )),
+        }
+    }
+    Ok(())
+}
+
+/// Sends `GET /` to the portal and returns the HTTP status code of the response.
+fn probe_portal(port: u16) -> anyhow::Result<u16> {
+    let portal_addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let mut stream = TcpStream::connect_timeout(&portal_addr, PORTAL_CHECK_TIMEOUT)?;
+    stream.set_read_timeout(Some(PORTAL_CHECK_TIMEOUT))?;
+    write!(stream, "GET / HTTP/1.0\r\nHost: 127.0.0.1:{port}\r\n\r\n")?;
+    // Only the status line, e.g. `HTTP/1.1 200 OK`, is needed
+    let mut status_line = String::new();
+    BufReader::new(stream).read_line(&mut status_line)?;
+    status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse().ok())
+        .with_context(|| format!("Unexpected HTTP response: {}", status_line.trim()))
+}
+
+async fn check_chains_rpc(
+    portal_config: &PortalRuntimeConfig,
+    strict: bool,
+    rpc_timeout: Duration,
+) -> anyhow::Result<()> {
+    for hyperchain in &portal_config.hyperchains_config.0 {
+        let network = &hyperchain.network;
+        if let Err(err) = check_rpc_url(&network.rpc_url, rpc_timeout).await {
+            let msg = msg_portal_chain_rpc_unreachable(&network.name, &network.rpc_url, err);
+            if strict {
+                anyhow::bail!(msg);
+            }
+            logger::warn(msg);
+        }
+    }
     Ok(())
 }
+
+async fn check_rpc_url(rpc_url: &str, rpc_timeout: Duration) -> anyhow::Result<u64> {
+    with_rpc_timeout(
+        rpc_timeout,
+        rpc_url,
+        "get chain id",
+        ethereum::get_chain_id(rpc_url.to_string()),
+    )
+    .await
+}
+
+async fn with_rpc_timeout<T>(
+    rpc_timeout: Duration,
+    rpc_url: &str,
+    operation: &str,
+    future: impl Future<Output = anyhow::Result<T>>,
+) -> anyhow::Result<T> {
+    tokio::time::timeout(rpc_timeout, future)
+        .await
+        .map_err(|_| PortalError::RpcTimeout {
+            operation: operation.to_string(),
+            rpc_url: rpc_url.to_string(),
+            timeout: rpc_timeout,
+        })?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chains(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_select_chains() {
+        let available = chains(&["era", "validium", "custom"]);
+        assert_eq!(select_chains(available.clone(), None).unwrap(), available);
+        assert_eq!(
+            select_chains(
+                available.clone(),
+                Some(chains(&["custom", "era"]).as_slice())
+            )
+            .unwrap(),
+            chains(&["custom", "era"])
+        );
+        assert_eq!(
+            select_chains(
+                available,
+                Some(chains(&["era", "custom", "era"]).as_slice())
+            )
+            .unwrap(),
+            chains(&["era", "custom"])
+        );
+    }
+
+    #[test]
+    fn test_select_chains_unknown_chain() {
+        let err = select_chains(
+            chains(&["era"]),
+            Some(chains(&["era", "missing"]).as_slice()),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            PortalError::UnknownChain { ref chain, ref available }
+                if chain == "missing" && available == &chains(&["era"])
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rpc_timeout_error() {
+        let timeout = Duration::from_millis(10);
+        let err = with_rpc_timeout(
+            timeout,
+            "http://127.0.0.1:8545",
+            "get chain id",
+            std::future::pending::<anyhow::Result<u64>>(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<PortalError>(),
+            Some(PortalError::RpcTimeout { timeout: err_timeout, .. }) if *err_timeout == timeout
+        ));
+    }
+}
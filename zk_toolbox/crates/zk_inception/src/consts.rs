@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 pub const AMOUNT_FOR_DISTRIBUTION_TO_WALLETS: u128 = 1000000000000000000000;
 
 pub const MINIMUM_BALANCE_FOR_WALLET: u128 = 5000000000000000000;
@@ -10,3 +12,11 @@ pub const BELLMAN_CUDA_DIR: &str = "era-bellman-cuda";
 pub const L2_BASE_TOKEN_ADDRESS: &str = "0x000000000000000000000000000000000000800A";
 pub const PORTAL_DOCKER_IMAGE: &str = "matterlabs/dapp-portal";
 pub const PORTAL_DOCKER_CONTAINER_PORT: u16 = 3000;
+pub const PORTAL_DEFAULT_DOCKER_PLATFORM: &str = "linux/amd64";
+/// Docker label holding the name of the ecosystem a container belongs to
+pub const DOCKER_ECOSYSTEM_LABEL: &str = "com.zksync.ecosystem";
+pub const PORTAL_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+pub const PORTAL_OPEN_MAX_ATTEMPTS: u32 = 60;
+pub const PORTAL_RPC_TIMEOUT: Duration = Duration::from_secs(10);
+pub const PORTAL_TOKEN_INFO_ATTEMPTS: u32 = 3;
+pub const PORTAL_TOKEN_INFO_RETRY_DELAY: Duration = Duration::from_secs(1);
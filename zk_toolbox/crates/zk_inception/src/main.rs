@@ -7,7 +7,8 @@ use common::{
     check_general_prerequisites,
     config::{global_config, init_global_config, GlobalConfig},
     error::log_error,
-    init_prompt_theme, logger,
+    init_prompt_theme,
+    logger::{self, LogLevel},
 };
 use config::EcosystemConfig;
 use xshell::Shell;
@@ -71,9 +72,12 @@ pub enum InceptionSubcommands {
 #[derive(Parser, Debug)]
 #[clap(next_help_heading = "Global options")]
 struct InceptionGlobalArgs {
-    /// Verbose mode
-    #[clap(short, long, global = true)]
-    verbose: bool,
+    /// Verbose mode, repeat (-vv) for trace output
+    #[clap(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Quiet mode, only warnings, errors and results are printed
+    #[clap(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
     /// Chain to use
     #[clap(long, global = true)]
     chain: Option<String>,
@@ -149,7 +153,12 @@ fn init_global_config_inner(
         }
     }
     init_global_config(GlobalConfig {
-        verbose: inception_args.verbose,
+        verbose: inception_args.verbose > 0,
+        log_level: if inception_args.quiet {
+            LogLevel::Quiet
+        } else {
+            LogLevel::from_verbosity(inception_args.verbose)
+        },
         chain_name: inception_args.chain.clone(),
         ignore_prerequisites: inception_args.ignore_prerequisites,
     });
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 
 use ethers::{
     types::{H160, U256},
@@ -147,6 +147,9 @@ pub(super) const MSG_CREATING_CHAIN_CONFIGURATIONS_SPINNER: &str =
     "Creating chain configurations...";
 pub(super) const MSG_CHAIN_ID_VALIDATOR_ERR: &str = "Invalid chain id";
 pub(super) const MSG_BASE_TOKEN_ADDRESS_VALIDATOR_ERR: &str = "Invalid base token address";
+pub(super) fn msg_base_token_address_is_zero_err(chain: &str) -> String {
+    format!("Base token address of chain {chain} is zero, but the chain is not ETH-based")
+}
 pub(super) const MSG_WALLET_CREATION_VALIDATOR_ERR: &str =
     "Localhost wallet is not supported for external networks";
 
@@ -228,11 +231,109 @@ pub(super) const MSG_PREPARING_EN_CONFIGS: &str = "Preparing External Node confi
 /// Portal related messages
 pub(super) const MSG_PORTAL_CONFIG_IS_EMPTY_ERR: &str = "Hyperchains config is empty";
 pub(super) const MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR: &str = "Failed to create portal config";
+pub(super) const MSG_PORTAL_FAILED_TO_PULL_DOCKER_ERR: &str = "Failed to pull portal docker image";
+pub(super) fn msg_portal_using_cached_image(error: &anyhow::Error) -> String {
+    format!("Failed to pull portal docker image, using the cached one: {error:#}")
+}
 pub(super) const MSG_PORTAL_FAILED_TO_RUN_DOCKER_ERR: &str =
     "Failed to run portal docker container";
 pub(super) fn msg_portal_starting_on(host: &str, port: u16) -> String {
     format!("Starting portal on http://{host}:{port}")
 }
+pub(super) const MSG_PORTAL_FAILED_TO_READ_CONFIG_ERR: &str = "Failed to read portal config";
+pub(super) const MSG_PORTAL_RUNTIME_CONFIG: &str = "Portal runtime config";
+pub(super) fn msg_portal_runtime_config(config: &str) -> String {
+    format!("Portal runtime config:\n{config}")
+}
+pub(super) const MSG_PORTAL_NO_VALID_CHAINS_ERR: &str =
+    "Failed to create any valid hyperchain config";
+pub(super) fn msg_portal_unknown_chain(chain: &str, available: &[String]) -> String {
+    format!("Chain {chain} doesn't exist, please choose from {available:?}")
+}
+pub(super) fn msg_portal_failed_to_load_chain(chain: &str) -> String {
+    format!("Skipping chain {chain} in portal config: failed to load chain config")
+}
+pub(super) fn msg_portal_failed_to_create_chain_config(
+    chain: &str,
+    error: &anyhow::Error,
+) -> String {
+    format!("Skipping chain {chain} in portal config: {error:#}")
+}
+pub(super) fn msg_portal_base_token_placeholder(chain: &str, error: &anyhow::Error) -> String {
+    format!("Failed to get base token info for chain {chain}, using a placeholder: {error:#}")
+}
+pub(super) fn msg_portal_base_token_retry(
+    chain: &str,
+    attempt: u32,
+    error: &anyhow::Error,
+) -> String {
+    format!("Attempt {attempt} to get base token info for chain {chain} failed: {error:#}")
+}
+pub(super) fn msg_portal_rpc_timeout(operation: &str, rpc_url: &str, timeout: &Duration) -> String {
+    format!(
+        "Timed out after {}s trying to {operation} via {rpc_url}, \
+        make sure the node is running or increase --rpc-timeout",
+        timeout.as_secs()
+    )
+}
+pub(super) fn msg_portal_failed_to_open_browser(
+    url: &str,
+    error: impl std::fmt::Display,
+) -> String {
+    format!("Failed to open {url} in the browser: {error}")
+}
+pub(super) fn msg_portal_container_running(containers: &str, port: u16) -> String {
+    format!("Portal container {containers} is running on port {port}")
+}
+pub(super) fn msg_portal_container_not_running(port: u16) -> String {
+    format!("No portal container of this ecosystem is running on port {port}")
+}
+pub(super) fn msg_portal_already_running(port: u16) -> String {
+    format!(
+        "Port {port} is already published by a running container, \
+        stop it first or use `zk_inception portal restart`"
+    )
+}
+pub(super) fn msg_portal_port_taken_by_other_container(port: u16) -> String {
+    format!(
+        "Port {port} is published by a container that is not the portal of this ecosystem, \
+        e.g. one started by an older zk_inception, stop it first with `docker stop`"
+    )
+}
+pub(super) fn msg_portal_not_running_starting(port: u16) -> String {
+    format!("No running container publishes port {port}, starting the portal instead")
+}
+pub(super) const MSG_PORTAL_FAILED_TO_RESTART_DOCKER_ERR: &str =
+    "Failed to restart portal docker container";
+pub(super) fn msg_portal_restarted_on(host: &str, port: u16) -> String {
+    format!("Portal restarted on http://{host}:{port}")
+}
+pub(super) fn msg_portal_platform_emulation(host_platform: &str, platform: &str) -> String {
+    format!(
+        "Portal image is not available for {host_platform}, running {platform} under emulation. \
+        Use --platform to choose the image platform explicitly"
+    )
+}
+pub(super) fn msg_portal_reachable(host: &str, port: u16, status: u16) -> String {
+    format!("Portal responded with HTTP {status} on http://{host}:{port}")
+}
+pub(super) fn msg_portal_unreachable(
+    host: &str,
+    port: u16,
+    error: impl std::fmt::Display,
+) -> String {
+    format!("Portal is not reachable on http://{host}:{port}: {error}")
+}
+pub(super) fn msg_portal_chain_rpc_reachable(chain: &str, rpc_url: &str, chain_id: u64) -> String {
+    format!("Chain {chain}: RPC {rpc_url} is reachable (chain id {chain_id})")
+}
+pub(super) fn msg_portal_chain_rpc_unreachable(
+    chain: &str,
+    rpc_url: &str,
+    error: impl std::fmt::Display,
+) -> String {
+    format!("Chain {chain}: RPC {rpc_url} is not reachable: {error}")
+}
 
 /// Forge utils related messages
 pub(super) const MSG_DEPLOYER_PK_NOT_SET_ERR: &str = "Deployer private key is not set";
@@ -0,0 +1,30 @@
+use common::ethereum;
+use config::ChainConfig;
+use ethers::types::Address;
+use types::{BaseToken, TokenInfo};
+
+use crate::messages::msg_base_token_address_is_zero_err;
+
+/// Returns the L1 address and token info of the chain base token.
+/// ETH is resolved locally, custom tokens are queried from L1.
+pub async fn resolve_base_token(
+    chain_config: &ChainConfig,
+    l1_rpc_url: &str,
+) -> anyhow::Result<(Address, TokenInfo)> {
+    if chain_config.base_token == BaseToken::eth() {
+        return Ok((Address::zero(), TokenInfo::eth()));
+    }
+    validate_base_token_address(chain_config)?;
+    let address = chain_config.base_token.address;
+    let token_info = ethereum::get_token_info(address, l1_rpc_url.to_string()).await?;
+    Ok((address, token_info))
+}
+
+/// Checks the base token address before it is used for L1 calls,
+/// a zero address would only surface as a confusing RPC error.
+pub fn validate_base_token_address(chain_config: &ChainConfig) -> anyhow::Result<()> {
+    if chain_config.base_token != BaseToken::eth() && chain_config.base_token.address.is_zero() {
+        anyhow::bail!(msg_base_token_address_is_zero_err(&chain_config.name));
+    }
+    Ok(())
+}
@@ -1,2 +1,3 @@
+pub mod base_token;
 pub mod forge;
 pub mod rocks_db;
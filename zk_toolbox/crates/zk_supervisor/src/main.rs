@@ -6,7 +6,8 @@ use common::{
     check_general_prerequisites,
     config::{global_config, init_global_config, GlobalConfig},
     error::log_error,
-    init_prompt_theme, logger,
+    init_prompt_theme,
+    logger::{self, LogLevel},
 };
 use config::EcosystemConfig;
 use messages::{
@@ -122,6 +123,7 @@ fn init_global_config_inner(shell: &Shell, args: &SupervisorGlobalArgs) -> anyho
 
     init_global_config(GlobalConfig {
         verbose: args.verbose,
+        log_level: LogLevel::from_verbosity(args.verbose.into()),
         chain_name: args.chain.clone(),
         ignore_prerequisites: args.ignore_prerequisites,
     });